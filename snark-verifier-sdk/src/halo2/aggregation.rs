@@ -2,28 +2,31 @@ use crate::{Plonk, BITS, LIMBS};
 #[cfg(feature = "display")]
 use ark_std::{end_timer, start_timer};
 use halo2_base::halo2_proofs::{
+    arithmetic::FieldExt,
     circuit::{Layouter, SimpleFloorPlanner, Value},
     halo2curves::bn256::{Bn256, Fq, Fr, G1Affine},
     plonk::{self, Circuit, Column, ConstraintSystem, Instance, Selector},
     poly::{commitment::ParamsProver, kzg::commitment::ParamsKZG},
 };
-use halo2_base::{Context, ContextParams};
+use halo2_base::{
+    gates::GateInstructions, AssignedValue, Context, ContextParams, QuantumCell::Existing,
+};
 use itertools::Itertools;
 use rand::Rng;
 use snark_verifier::{
     loader::{
         self,
-        halo2::halo2_ecc::{self, ecc::EccChip},
+        halo2::halo2_ecc::{self, ecc::{EccChip, EccPoint}},
         native::NativeLoader,
     },
     pcs::{
-        kzg::{Bdfg21, Kzg, KzgAccumulator, KzgAs, KzgSuccinctVerifyingKey},
+        kzg::{Bdfg21, Gwc19, Kzg, KzgAccumulator, KzgAs, KzgSuccinctVerifyingKey},
         AccumulationScheme, AccumulationSchemeProver, MultiOpenScheme, PolynomialCommitmentScheme,
     },
-    util::arithmetic::fe_to_limbs,
+    util::arithmetic::{fe_from_limbs, fe_to_limbs},
     verifier::PlonkVerifier,
 };
-use std::{fs::File, rc::Rc};
+use std::{cell::RefCell, fs::File, marker::PhantomData, rc::Rc};
 
 use super::{CircuitExt, PoseidonTranscript, Snark, SnarkWitness, POSEIDON_SPEC};
 
@@ -42,17 +45,211 @@ pub fn load_verify_circuit_degree() -> u32 {
     params.degree
 }
 
+/// Maximum domain size exponent `k` (`n = 2^k`) supported when a snark's
+/// degree is loaded as an in-circuit witness via
+/// [`PreprocessedAndDomainAsWitness`]. It must not exceed `Fr::S` (the
+/// two-adicity of `Fr`), which `derive_domain_as_witness` asserts.
+pub const K_MAX: u32 = 24;
+
+/// Whether `aggregate` treats each snark's domain (`n` and `omega`) as a
+/// `Protocol` constant baked in at load time (`Constant`, the original
+/// behavior), or as an in-circuit `Scalar` witness derived from a witnessed
+/// `k = log2(n)` (`PreprocessedAndDomainAsWitness`). The latter lets one
+/// fixed aggregation circuit verify snarks of differing degree (up to
+/// `K_MAX`) without forcing every aggregated snark to share a statically
+/// known degree, and without exposing `omega` as a public input the way a
+/// universal verifier otherwise would have to.
+///
+/// `PreprocessedAndDomainAsWitness` is currently a library-only primitive: both
+/// `AggregationCircuit` and `PublicAggregationCircuit` call `aggregate` with `DomainAs::Constant`
+/// for every snark and discard the `k`/vkey-digest values `aggregate` returns for it. Driving a
+/// concrete circuit off a witnessed `k` additionally needs that circuit to accept and constrain a
+/// per-snark `k`, which neither of those two circuits' public APIs do yet -- so wire one up before
+/// relying on this variant outside tests.
+#[derive(Clone, Copy, Debug)]
+pub enum DomainAs {
+    Constant,
+    PreprocessedAndDomainAsWitness { k: Value<u32> },
+}
+
+/// Witnesses `k`, derives `n = 2^k` and the `2^k`-th root of unity `omega`
+/// inside the circuit, and returns `(k, n, omega)`.
+///
+/// Precomputes, natively, `omega_max` (the `2^K_MAX`-th root of unity) and
+/// the table `omega_max^(2^i)` for `i = 0..=K_MAX`; `table[K_MAX - k]` is
+/// then exactly `omega`, so deriving it is a single constant-table select on
+/// the bit-decomposition of `K_MAX - k` (the same trick, with a table of
+/// field constants instead of EC points, as the windowed fixed-base MSM
+/// select). Deriving `n = 2^k` is the analogous select against the table of
+/// powers of two.
+fn derive_domain_as_witness<'a>(
+    loader: &Rc<Halo2Loader<'a>>,
+    k: Value<u32>,
+) -> (
+    loader::halo2::Scalar<'a, G1Affine, BaseFieldEccChip>,
+    loader::halo2::Scalar<'a, G1Affine, BaseFieldEccChip>,
+    loader::halo2::Scalar<'a, G1Affine, BaseFieldEccChip>,
+) {
+    let s = Fr::S;
+    assert!(K_MAX <= s, "K_MAX must not exceed Fr::S");
+    let omega_max = Fr::root_of_unity().pow_vartime([1u64 << (s - K_MAX)]);
+    let mut omega = omega_max;
+    let omega_table = (0..=K_MAX)
+        .map(|_| {
+            let cur = omega;
+            omega = omega.square();
+            cur
+        })
+        .collect_vec();
+    let pow2_table = (0..=K_MAX).map(|i| Fr::from(1u64 << i)).collect_vec();
+    // Bits needed to index a table of `K_MAX + 1` entries (not `K_MAX` itself) -- `select_from_table`
+    // pads its table up to `2^num_bits` entries, so this has to be the smallest `num_bits` with
+    // `2^num_bits >= omega_table.len()`, not a bit-width of `K_MAX` the way `u32::leading_zeros` would
+    // give it.
+    let num_bits = table_index_bits(omega_table.len());
+
+    // `K_MAX - k` below is a native `u32` subtraction that underflows for
+    // `k > K_MAX`; guard it here instead of leaving the underflow to panic
+    // (debug) or silently wrap (release) before it ever reaches a constraint.
+    k.assert_if_known(|&k| k <= K_MAX);
+
+    let gate = loader.scalar_chip();
+    let mut ctx = loader.ctx_mut();
+
+    let k_max_minus_k = gate
+        .assign_integer(&mut ctx, k.map(|k| Fr::from((K_MAX - k) as u64)))
+        .expect("assign K_MAX - k");
+    let idx_bits = GateInstructions::num_to_bits(gate, &mut ctx, &k_max_minus_k, num_bits);
+    let omega = select_from_table(gate, &mut ctx, &omega_table, &idx_bits);
+
+    let k_assigned = gate.assign_integer(&mut ctx, k.map(|k| Fr::from(k as u64))).expect("assign k");
+    let k_bits = GateInstructions::num_to_bits(gate, &mut ctx, &k_assigned, num_bits);
+    let n = select_from_table(gate, &mut ctx, &pow2_table, &k_bits);
+
+    drop(ctx);
+    (
+        loader.scalar_from_assigned(k_assigned),
+        loader.scalar_from_assigned(n),
+        loader.scalar_from_assigned(omega),
+    )
+}
+
+/// The smallest `num_bits` such that a binary-tree select over `bits.len() == num_bits` selector
+/// bits (halving the candidate list once per bit) can resolve a table of `len` entries down to a
+/// single candidate, i.e. the smallest `num_bits` with `2^num_bits >= len`.
+fn table_index_bits(len: usize) -> usize {
+    assert!(len > 0, "table must be non-empty");
+    let mut num_bits = 0;
+    while (1usize << num_bits) < len {
+        num_bits += 1;
+    }
+    num_bits
+}
+
+/// Selects `table[bits]` (`bits` little-endian) where every entry of `table`
+/// is a circuit constant, via a binary-tree of selects.
+///
+/// `table` need not have a power-of-two length: it's padded up to `2^bits.len()` entries (by
+/// repeating its last entry) before the select tree runs, since `chunks(2)` on an odd-length
+/// candidate list would otherwise produce a trailing chunk of length 1 and panic on `pair[1]`.
+/// Callers are responsible for only ever decomposing indices that land within the real,
+/// un-padded `table`, e.g. via `table_index_bits(table.len())`.
+fn select_from_table(
+    gate: &impl GateInstructions<Fr>,
+    ctx: &mut Context<Fr>,
+    table: &[Fr],
+    bits: &[AssignedValue<Fr>],
+) -> AssignedValue<Fr> {
+    assert!(!table.is_empty(), "table must be non-empty");
+    let padded_len = 1usize << bits.len();
+    assert!(table.len() <= padded_len, "not enough bits to index the whole table");
+    let last = *table.last().unwrap();
+    let mut candidates = (0..padded_len)
+        .map(|i| gate.load_constant(ctx, *table.get(i).unwrap_or(&last)))
+        .collect_vec();
+    for bit in bits {
+        candidates = candidates
+            .chunks(2)
+            .map(|pair| gate.select(ctx, Existing(&pair[1]), Existing(&pair[0]), Existing(bit)))
+            .collect_vec();
+    }
+    candidates.pop().unwrap()
+}
+
+/// Native-side counterpart of `accumulator_from_limbs`, used by `AggregationCircuit::new` when
+/// precomputing the public output outside the circuit: decodes a [`KzgAccumulator`] back out of
+/// the `4 * LIMBS` accumulator limbs exposed as the leading instances of a snark that is itself
+/// the output of an aggregation circuit.
+fn native_accumulator_from_limbs(limbs: &[Fr]) -> KzgAccumulator<G1Affine, NativeLoader> {
+    assert_eq!(limbs.len(), 4 * LIMBS);
+    let to_point = |limbs: &[Fr]| {
+        let x = fe_from_limbs::<_, _, LIMBS, BITS>(limbs[..LIMBS].try_into().unwrap());
+        let y = fe_from_limbs::<_, _, LIMBS, BITS>(limbs[LIMBS..].try_into().unwrap());
+        G1Affine::from_xy(x, y).unwrap()
+    };
+    KzgAccumulator { lhs: to_point(&limbs[..2 * LIMBS]), rhs: to_point(&limbs[2 * LIMBS..]) }
+}
+
+/// Reconstructs a [`KzgAccumulator`] whose `lhs`/`rhs` coordinates were already assigned as the
+/// first `4 * LIMBS` instances of a snark -- i.e. the snark is itself the output of an
+/// aggregation circuit, and those instances are its accumulator limbs rather than an ordinary
+/// public input. Used by `aggregate` to fold a previously-verified accumulator into the current
+/// one instead of silently dropping it.
+fn accumulator_from_limbs<'a>(
+    loader: &Rc<Halo2Loader<'a>>,
+    limbs: &[loader::halo2::Scalar<'a, G1Affine, BaseFieldEccChip>],
+) -> KzgAccumulator<G1Affine, Rc<Halo2Loader<'a>>> {
+    assert_eq!(limbs.len(), 4 * LIMBS);
+    let assign_point = |limbs: &[loader::halo2::Scalar<'a, G1Affine, BaseFieldEccChip>]| {
+        let ecc_chip = loader.ecc_chip();
+        let mut ctx = loader.ctx_mut();
+        let x = ecc_chip.field_chip().assign_integer_from_limbs(
+            &mut ctx,
+            &limbs[..LIMBS].iter().map(|limb| limb.assigned()).collect_vec(),
+        );
+        let y = ecc_chip.field_chip().assign_integer_from_limbs(
+            &mut ctx,
+            &limbs[LIMBS..].iter().map(|limb| limb.assigned()).collect_vec(),
+        );
+        loader.ec_point_from_assigned(EccPoint::construct(x, y))
+    };
+    KzgAccumulator { lhs: assign_point(&limbs[..2 * LIMBS]), rhs: assign_point(&limbs[2 * LIMBS..]) }
+}
+
+/// Per-snark witnesses `aggregate` assigns but doesn't itself constrain against anything
+/// external, returned so callers can: `transcript_initial_state` is the assigned digest of the
+/// snark's preprocessed verifying key (baked into its `Protocol`, and absorbed into the
+/// Fiat-Shamir transcript same as the native verifier), and `k` is the witnessed domain-size
+/// exponent whenever that snark's domain was loaded via
+/// [`DomainAs::PreprocessedAndDomainAsWitness`] (`None` for [`DomainAs::Constant`]). Without a
+/// caller constraining these -- e.g. exposing them as instances and checking them against the
+/// actual vkey being verified -- they're witnesses nobody checks.
+pub struct PreprocessedAndDomain<'a> {
+    pub transcript_initial_state: Option<loader::halo2::Scalar<'a, G1Affine, BaseFieldEccChip>>,
+    pub k: Option<loader::halo2::Scalar<'a, G1Affine, BaseFieldEccChip>>,
+}
+
 /// Core function used in `synthesize` to aggregate multiple `snarks`.
-///  
-/// Returns the assigned instances of previous snarks (all concatenated together) and the new final pair that needs to be verified in a pairing check
+///
+/// `has_accumulator[i]` marks whether `snarks[i]` is itself the output of an aggregation
+/// circuit; if so, its existing accumulator (its first `4 * LIMBS` instances) is decoded and
+/// folded in alongside the accumulator freshly produced by verifying it, enabling recursive,
+/// two-layer aggregation.
+///
+/// Returns the assigned instances of previous snarks (all concatenated together), the new final
+/// pair that needs to be verified in a pairing check, and, per snark, the preprocessed vkey
+/// digest and witnessed domain size (see [`PreprocessedAndDomain`]).
 pub fn aggregate<'a, PCS>(
     svk: &PCS::SuccinctVerifyingKey,
     loader: &Rc<Halo2Loader<'a>>,
     snarks: &[SnarkWitness],
     as_proof: Value<&'_ [u8]>,
+    domain_as_witness: &[DomainAs],
+    has_accumulator: &[bool],
 ) -> (
     Vec<loader::halo2::Scalar<'a, G1Affine, BaseFieldEccChip>>,
     KzgAccumulator<G1Affine, Rc<Halo2Loader<'a>>>,
+    Vec<PreprocessedAndDomain<'a>>,
 )
 where
     PCS: PolynomialCommitmentScheme<
@@ -72,6 +269,7 @@ where
 
     // TODO pre-allocate capacity better
     let mut previous_instances = vec![];
+    let mut preprocessed_and_domain = vec![];
     let mut transcript = PoseidonTranscript::<Rc<Halo2Loader<'a>>, _>::from_spec(
         loader,
         Value::unknown(),
@@ -80,8 +278,22 @@ where
 
     let mut accumulators = snarks
         .iter()
-        .flat_map(|snark| {
-            let protocol = snark.protocol.loaded(loader);
+        .zip(domain_as_witness.iter())
+        .zip(has_accumulator.iter())
+        .flat_map(|((snark, domain_as_witness), has_accumulator)| {
+            let mut protocol = snark.protocol.loaded(loader);
+            let k = if let DomainAs::PreprocessedAndDomainAsWitness { k } = domain_as_witness {
+                let (k, n, omega) = derive_domain_as_witness(loader, *k);
+                protocol.domain.n = n;
+                protocol.domain.omega = omega;
+                Some(k)
+            } else {
+                None
+            };
+            preprocessed_and_domain.push(PreprocessedAndDomain {
+                transcript_initial_state: protocol.transcript_initial_state.clone(),
+                k,
+            });
             // TODO use 1d vector
             let instances = assign_instances(&snark.instances);
 
@@ -90,12 +302,18 @@ where
             transcript.new_stream(snark.proof());
             let proof =
                 Plonk::<PCS>::read_proof(svk, &protocol, &instances, &mut transcript).unwrap();
-            let accumulator =
-                Plonk::<PCS>::succinct_verify(svk, &protocol, &instances, &proof).unwrap();
+            let mut accumulators = Plonk::<PCS>::succinct_verify(svk, &protocol, &instances, &proof)
+                .unwrap()
+                .into_iter()
+                .collect_vec();
+
+            if *has_accumulator {
+                accumulators.push(accumulator_from_limbs(loader, &instances[0][..4 * LIMBS]));
+            }
 
             previous_instances.extend(instances.into_iter().flatten());
 
-            accumulator
+            accumulators
         })
         .collect_vec();
 
@@ -108,7 +326,38 @@ where
         accumulators.pop().unwrap()
     };
 
-    (previous_instances, accumulator)
+    (previous_instances, accumulator, preprocessed_and_domain)
+}
+
+/// Which multi-open scheme a serialized [`AggregationConfigParams`] was generated for. Column
+/// layout doesn't depend on this choice (see [`AggregationCircuit`]'s doc), but a config file is
+/// otherwise indistinguishable between a SHPLONK and a GWC19 aggregation circuit, so this is what
+/// lets a reader of `verify_circuit.config` alone tell the two apart instead of assuming SHPLONK.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum MultiOpenSchemeChoice {
+    Bdfg21,
+    Gwc19,
+}
+
+impl Default for MultiOpenSchemeChoice {
+    fn default() -> Self {
+        Self::Bdfg21
+    }
+}
+
+/// Associates an [`AggregationCircuit`]'s `AS` type parameter with the [`MultiOpenSchemeChoice`]
+/// it corresponds to, so `configure` can check a loaded config's declared scheme against the
+/// scheme the circuit is actually being built for.
+pub trait MultiOpenSchemeKind {
+    const CHOICE: MultiOpenSchemeChoice;
+}
+
+impl MultiOpenSchemeKind for Kzg<Bn256, Bdfg21> {
+    const CHOICE: MultiOpenSchemeChoice = MultiOpenSchemeChoice::Bdfg21;
+}
+
+impl MultiOpenSchemeKind for Kzg<Bn256, Gwc19> {
+    const CHOICE: MultiOpenSchemeChoice = MultiOpenSchemeChoice::Gwc19;
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -121,6 +370,8 @@ pub struct AggregationConfigParams {
     pub lookup_bits: usize,
     pub limb_bits: usize,
     pub num_limbs: usize,
+    #[serde(default)]
+    pub multi_open_scheme: MultiOpenSchemeChoice,
 }
 
 #[derive(Clone)]
@@ -169,26 +420,67 @@ impl AggregationConfig {
     }
 }
 
+/// Per-phase advice-column allocation counts (`loader.ctx().advice_alloc`) recorded after
+/// synthesizing an [`AggregationCircuit`], typically during key generation. Floor-planning a
+/// circuit derives these from the witnesses it happens to be fed, so two synthesize calls over
+/// *different* snarks are not guaranteed to land on the same columns; persisting the key-gen
+/// run's break points and reusing them for every later proving run keeps the layout underneath a
+/// single serialized proving key fixed, which is what makes that proving key reusable at all.
+pub type BreakPoints = Vec<Vec<(usize, usize)>>;
+
 /// Aggregation circuit that does not re-expose any public inputs from aggregated snarks
 ///
 /// This is mostly a reference implementation. In practice one will probably need to re-implement the circuit for one's particular use case with specific instance logic.
+///
+/// Generic over the multi-open scheme `AS` used for the final KZG accumulation, so the same
+/// circuit shape works for both [`Bdfg21`] (SHPLONK, the default) and [`Gwc19`] proofs -- only
+/// `AggregationCircuit::new`'s accumulation-proof step and `synthesize`'s in-circuit verification
+/// depend on `AS`, everything else (instance layout, config) is unaffected by the choice. An IPA
+/// backend is deliberately not offered here: IPA has no pairing and lives over a different curve
+/// entirely, so it cannot be slotted into this struct's fixed `Bn256`/`G1Affine` accumulator
+/// representation -- it would need its own circuit, not just a new `AS`.
 #[derive(Clone)]
-pub struct AggregationCircuit {
+pub struct AggregationCircuit<AS = Kzg<Bn256, Bdfg21>> {
     svk: Svk,
     snarks: Vec<SnarkWitness>,
+    has_accumulator: Vec<bool>,
     instances: Vec<Fr>,
     as_proof: Value<Vec<u8>>,
+    /// `Some` once `synthesize` has captured a layout (or one has been injected via
+    /// `with_break_points`); `None` means `synthesize` should record whatever layout it derives
+    /// rather than assert against a previously recorded one.
+    break_points: RefCell<Option<BreakPoints>>,
+    _marker: PhantomData<AS>,
 }
 
-impl AggregationCircuit {
+impl<AS> AggregationCircuit<AS>
+where
+    AS: PolynomialCommitmentScheme<
+            G1Affine,
+            NativeLoader,
+            Accumulator = KzgAccumulator<G1Affine, NativeLoader>,
+        > + MultiOpenScheme<G1Affine, NativeLoader>
+        + AccumulationScheme<G1Affine, NativeLoader, Accumulator = KzgAccumulator<G1Affine, NativeLoader>>
+        + AccumulationSchemeProver<G1Affine>
+        + for<'a> PolynomialCommitmentScheme<
+            G1Affine,
+            Rc<Halo2Loader<'a>>,
+            Accumulator = KzgAccumulator<G1Affine, Rc<Halo2Loader<'a>>>,
+        > + for<'a> MultiOpenScheme<G1Affine, Rc<Halo2Loader<'a>>>,
+{
+    /// `snarks` is paired with a flag marking whether that snark is itself the output of an
+    /// aggregation circuit; if so, its existing accumulator (its first `4 * LIMBS` instances) is
+    /// decoded and folded in alongside the accumulator produced by verifying it, instead of
+    /// being treated as an ordinary public input -- this is what lets aggregation circuits be
+    /// nested into a recursive, multi-layer tree.
     pub fn new(
         params: &ParamsKZG<Bn256>,
-        snarks: impl IntoIterator<Item = Snark>,
+        snarks: impl IntoIterator<Item = (Snark, bool)>,
         transcript_write: &mut PoseidonTranscript<NativeLoader, Vec<u8>>,
         rng: &mut impl Rng,
     ) -> Self {
+        let (snarks, has_accumulator): (Vec<_>, Vec<_>) = snarks.into_iter().unzip();
         let svk = params.get_g()[0].into();
-        let snarks = snarks.into_iter().collect_vec();
 
         // TODO: this is all redundant calculation to get the public output
         // Halo2 should just be able to expose public output to instance column directly
@@ -196,23 +488,32 @@ impl AggregationCircuit {
             PoseidonTranscript::<NativeLoader, &[u8]>::from_spec(&[], POSEIDON_SPEC.clone());
         let accumulators = snarks
             .iter()
-            .flat_map(|snark| {
+            .zip(has_accumulator.iter())
+            .flat_map(|(snark, has_accumulator)| {
                 transcript_read.new_stream(snark.proof.as_slice());
-                let proof = Shplonk::read_proof(
+                let proof = Plonk::<AS>::read_proof(
                     &svk,
                     &snark.protocol,
                     &snark.instances,
                     &mut transcript_read,
                 )
                 .unwrap();
-                Shplonk::succinct_verify(&svk, &snark.protocol, &snark.instances, &proof).unwrap()
+                let mut accumulators =
+                    Plonk::<AS>::succinct_verify(&svk, &snark.protocol, &snark.instances, &proof)
+                        .unwrap()
+                        .into_iter()
+                        .collect_vec();
+                if *has_accumulator {
+                    accumulators
+                        .push(native_accumulator_from_limbs(&snark.instances[0][..4 * LIMBS]));
+                }
+                accumulators
             })
             .collect_vec();
 
         let (accumulator, as_proof) = {
             transcript_write.clear();
-            // We always use SHPLONK for accumulation scheme when aggregating proofs
-            let accumulator = KzgAs::<Kzg<Bn256, Bdfg21>>::create_proof(
+            let accumulator = KzgAs::<AS>::create_proof(
                 &Default::default(),
                 &accumulators,
                 transcript_write,
@@ -228,11 +529,29 @@ impl AggregationCircuit {
         Self {
             svk,
             snarks: snarks.into_iter().map_into().collect(),
+            has_accumulator,
             instances,
             as_proof: Value::known(as_proof),
+            break_points: RefCell::new(None),
+            _marker: PhantomData,
         }
     }
 
+    /// Injects break points recorded during an earlier synthesis of this circuit (e.g. the one
+    /// run as part of key generation), so this instance reuses that exact column layout instead
+    /// of deriving a fresh one from its own snarks. Needed to keep a serialized proving key valid
+    /// across separate key-generation and proving processes.
+    pub fn with_break_points(self, break_points: BreakPoints) -> Self {
+        *self.break_points.borrow_mut() = Some(break_points);
+        self
+    }
+
+    /// The break points recorded the last time this circuit was synthesized, or injected via
+    /// `with_break_points`. `None` until one of those has happened.
+    pub fn break_points(&self) -> Option<BreakPoints> {
+        self.break_points.borrow().clone()
+    }
+
     pub fn accumulator_indices() -> Vec<(usize, usize)> {
         (0..4 * LIMBS).map(|idx| (0, idx)).collect()
     }
@@ -250,7 +569,21 @@ impl AggregationCircuit {
     }
 }
 
-impl CircuitExt<Fr> for AggregationCircuit {
+impl<AS> CircuitExt<Fr> for AggregationCircuit<AS>
+where
+    AS: PolynomialCommitmentScheme<
+            G1Affine,
+            NativeLoader,
+            Accumulator = KzgAccumulator<G1Affine, NativeLoader>,
+        > + MultiOpenScheme<G1Affine, NativeLoader>
+        + AccumulationScheme<G1Affine, NativeLoader, Accumulator = KzgAccumulator<G1Affine, NativeLoader>>
+        + AccumulationSchemeProver<G1Affine>
+        + for<'a> PolynomialCommitmentScheme<
+            G1Affine,
+            Rc<Halo2Loader<'a>>,
+            Accumulator = KzgAccumulator<G1Affine, Rc<Halo2Loader<'a>>>,
+        > + for<'a> MultiOpenScheme<G1Affine, Rc<Halo2Loader<'a>>>,
+{
     fn num_instance() -> Vec<usize> {
         // [..lhs, ..rhs]
         vec![4 * LIMBS]
@@ -269,7 +602,22 @@ impl CircuitExt<Fr> for AggregationCircuit {
     }
 }
 
-impl Circuit<Fr> for AggregationCircuit {
+impl<AS> Circuit<Fr> for AggregationCircuit<AS>
+where
+    AS: PolynomialCommitmentScheme<
+            G1Affine,
+            NativeLoader,
+            Accumulator = KzgAccumulator<G1Affine, NativeLoader>,
+        > + MultiOpenScheme<G1Affine, NativeLoader>
+        + AccumulationScheme<G1Affine, NativeLoader, Accumulator = KzgAccumulator<G1Affine, NativeLoader>>
+        + AccumulationSchemeProver<G1Affine>
+        + for<'a> PolynomialCommitmentScheme<
+            G1Affine,
+            Rc<Halo2Loader<'a>>,
+            Accumulator = KzgAccumulator<G1Affine, Rc<Halo2Loader<'a>>>,
+        > + for<'a> MultiOpenScheme<G1Affine, Rc<Halo2Loader<'a>>>
+        + MultiOpenSchemeKind,
+{
     type Config = AggregationConfig;
     type FloorPlanner = SimpleFloorPlanner;
 
@@ -277,8 +625,11 @@ impl Circuit<Fr> for AggregationCircuit {
         Self {
             svk: self.svk,
             snarks: self.snarks.iter().map(SnarkWitness::without_witnesses).collect(),
+            has_accumulator: self.has_accumulator.clone(),
             instances: Vec::new(),
             as_proof: Value::unknown(),
+            break_points: RefCell::new(self.break_points.borrow().clone()),
+            _marker: PhantomData,
         }
     }
 
@@ -289,6 +640,13 @@ impl Circuit<Fr> for AggregationCircuit {
             File::open(path.as_str()).unwrap_or_else(|_| panic!("{path:?} does not exist")),
         )
         .unwrap();
+        assert_eq!(
+            params.multi_open_scheme,
+            AS::CHOICE,
+            "verify_circuit.config was generated for {:?}, but this circuit is {:?}",
+            params.multi_open_scheme,
+            AS::CHOICE
+        );
 
         AggregationConfig::configure(meta, params)
     }
@@ -313,7 +671,7 @@ impl Circuit<Fr> for AggregationCircuit {
                 }
                 #[cfg(feature = "display")]
                 let witness_time = start_timer!(|| "Witness Collection");
-                let ctx = Context::new(
+                let mut ctx = Context::new(
                     region,
                     ContextParams {
                         max_rows: config.gate().max_rows,
@@ -321,14 +679,30 @@ impl Circuit<Fr> for AggregationCircuit {
                         fixed_columns: config.gate().constants.clone(),
                     },
                 );
+                // If break points were injected via `with_break_points`, seed the fresh context's
+                // allocation state with them *before* anything is assigned, so the column chosen
+                // for each cell below is driven by that recorded layout rather than by whatever
+                // this run's own witnesses would otherwise produce. Without this, `break_points`
+                // only ever got compared against the layout after the fact -- too late to keep it
+                // from drifting.
+                if let Some(break_points) = &*self.break_points.borrow() {
+                    ctx.advice_alloc = break_points.clone();
+                }
 
                 let ecc_chip = config.ecc_chip();
                 let loader = Halo2Loader::new(ecc_chip, ctx);
-                let (_, KzgAccumulator { lhs, rhs }) = aggregate::<Kzg<Bn256, Bdfg21>>(
+                // Every snark's domain is still loaded as a `Protocol` constant here, so this
+                // doesn't yet deliver a single fixed circuit that verifies snarks of differing
+                // degree -- the `PreprocessedAndDomain` this discards (vkey digest, witnessed
+                // `k`) is unused until a caller switches to `DomainAs::PreprocessedAndDomainAsWitness`
+                // and constrains those witnesses (see `derive_domain_as_witness`).
+                let (_, KzgAccumulator { lhs, rhs }, _) = aggregate::<AS>(
                     &self.svk,
                     &loader,
                     &self.snarks,
                     self.as_proof(),
+                    &vec![DomainAs::Constant; self.snarks.len()],
+                    &self.has_accumulator,
                 );
 
                 let lhs = lhs.assigned();
@@ -340,6 +714,20 @@ impl Circuit<Fr> for AggregationCircuit {
                 #[cfg(feature = "display")]
                 println!("Advice columns used: {}", loader.ctx().advice_alloc[0][0].0 + 1);
 
+                // Record the column layout this synthesis landed on. When break points were
+                // injected up front, `ctx.advice_alloc` was already seeded with them above, so
+                // `recorded` should simply echo them back -- the assert below is just a guard
+                // against the injected layout somehow not being reproducible, not how the layout
+                // is actually kept fixed.
+                let recorded = loader.ctx().advice_alloc.clone();
+                if let Some(expected) = &*self.break_points.borrow() {
+                    assert_eq!(
+                        expected, &recorded,
+                        "circuit layout drifted from the break points it was constructed with"
+                    );
+                }
+                *self.break_points.borrow_mut() = Some(recorded);
+
                 assigned_instances = lhs
                     .x
                     .truncation
@@ -373,3 +761,576 @@ impl Circuit<Fr> for AggregationCircuit {
         Ok(())
     }
 }
+
+/// [`AggregationCircuit`] instantiated with GWC19 instead of SHPLONK as the multi-open scheme.
+pub type Gwc19AggregationCircuit = AggregationCircuit<Kzg<Bn256, Gwc19>>;
+
+/// Aggregation circuit that, in addition to the final pair accumulator, re-exposes every
+/// instance of the snarks it aggregates, concatenated after the accumulator limbs.
+///
+/// When one of the aggregated snarks is itself the output of [`AggregationCircuit`], its first
+/// `4 * LIMBS` instances are the *inner* accumulator limbs: those limbs get folded into this
+/// circuit's own accumulator by `aggregate`, so re-exposing them verbatim would both be
+/// redundant and would leak the already-verified inner accumulator as if it were an ordinary
+/// public input of the inner snark. `has_accumulator` marks, per snark, whether to skip that
+/// prefix when forwarding instances.
+#[derive(Clone)]
+pub struct PublicAggregationCircuit {
+    svk: Svk,
+    snarks: Vec<SnarkWitness>,
+    has_accumulator: Vec<bool>,
+    /// `[..accumulator limbs, ..forwarded instances of snarks]`
+    instances: Vec<Fr>,
+    as_proof: Value<Vec<u8>>,
+}
+
+thread_local! {
+    /// See the comment on `CircuitExt::num_instance` below for why this exists. `None` once
+    /// consumed, so a `num_instance()` that isn't immediately preceded by a `new()` on this
+    /// thread panics loudly instead of silently reading a stale count left by some other
+    /// circuit's construction.
+    static NUM_INSTANCE: std::cell::Cell<Option<usize>> = std::cell::Cell::new(None);
+}
+
+impl PublicAggregationCircuit {
+    pub fn new(
+        params: &ParamsKZG<Bn256>,
+        snarks: impl IntoIterator<Item = (Snark, bool)>,
+        transcript_write: &mut PoseidonTranscript<NativeLoader, Vec<u8>>,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let (snarks, has_accumulator): (Vec<_>, Vec<_>) = snarks.into_iter().unzip();
+        let svk = params.get_g()[0].into();
+
+        let mut transcript_read =
+            PoseidonTranscript::<NativeLoader, &[u8]>::from_spec(&[], POSEIDON_SPEC.clone());
+        let mut previous_instances = vec![];
+        let accumulators = snarks
+            .iter()
+            .zip(has_accumulator.iter())
+            .flat_map(|(snark, has_accumulator)| {
+                transcript_read.new_stream(snark.proof.as_slice());
+                let proof = Shplonk::read_proof(
+                    &svk,
+                    &snark.protocol,
+                    &snark.instances,
+                    &mut transcript_read,
+                )
+                .unwrap();
+                let skip = if *has_accumulator { 4 * LIMBS } else { 0 };
+                previous_instances.extend(snark.instances.iter().flatten().copied().skip(skip));
+                let mut accumulators =
+                    Shplonk::succinct_verify(&svk, &snark.protocol, &snark.instances, &proof)
+                        .unwrap()
+                        .into_iter()
+                        .collect_vec();
+                if *has_accumulator {
+                    accumulators
+                        .push(native_accumulator_from_limbs(&snark.instances[0][..4 * LIMBS]));
+                }
+                accumulators
+            })
+            .collect_vec();
+
+        let (accumulator, as_proof) = {
+            transcript_write.clear();
+            // We always use SHPLONK for accumulation scheme when aggregating proofs
+            let accumulator = KzgAs::<Kzg<Bn256, Bdfg21>>::create_proof(
+                &Default::default(),
+                &accumulators,
+                transcript_write,
+                rng,
+            )
+            .unwrap();
+            (accumulator, transcript_write.stream_mut().split_off(0))
+        };
+
+        let KzgAccumulator { lhs, rhs } = accumulator;
+        let mut instances =
+            [lhs.x, lhs.y, rhs.x, rhs.y].map(fe_to_limbs::<_, _, LIMBS, BITS>).concat();
+        instances.extend(previous_instances);
+        NUM_INSTANCE.with(|cell| cell.set(Some(instances.len())));
+
+        Self {
+            svk,
+            snarks: snarks.into_iter().map_into().collect(),
+            has_accumulator,
+            instances,
+            as_proof: Value::known(as_proof),
+        }
+    }
+
+    pub fn as_proof(&self) -> Value<&[u8]> {
+        self.as_proof.as_ref().map(Vec::as_slice)
+    }
+
+    /// Self-contained alternative to `CircuitExt::num_instance()` below: that trait method is a
+    /// bare associated function, so it can't read `self` and instead falls back to the fragile
+    /// `new()`-then-`num_instance()` thread-local handshake. Whenever a concrete circuit value is
+    /// already in hand (the common case), prefer this method instead -- it reads the count
+    /// straight off `self.instances` and carries none of the single-circuit-per-thread hazard.
+    pub fn num_instances(&self) -> Vec<usize> {
+        vec![self.instances.len()]
+    }
+}
+
+impl CircuitExt<Fr> for PublicAggregationCircuit {
+    // `CircuitExt::num_instance` is a bare associated function -- it can't take `&self` -- yet
+    // the number of forwarded instances genuinely depends on which snarks were passed to `new`.
+    // `new` stashes that count in this thread-local as a side effect, so `num_instance` (always
+    // called on the same thread shortly after construction, e.g. right before proving) can read
+    // it back without a `self` receiver.
+    //
+    // This only holds up for exactly one `PublicAggregationCircuit` under construction per
+    // thread at a time, consumed before the next `new()` -- constructing a second one first
+    // (e.g. in a loop, or on a pooled thread) before reading this one's count would silently
+    // hand back the wrong value. `take()` here makes that misuse loud instead of silent: once
+    // consumed, a stray call with no matching `new()` panics rather than returning stale data.
+    fn num_instance() -> Vec<usize> {
+        vec![NUM_INSTANCE.with(|cell| cell.take()).expect(
+            "PublicAggregationCircuit::num_instance() called with no matching ::new() \
+             immediately before it on this thread -- construct the circuit and read \
+             num_instance() right away, one at a time, before building the next one",
+        )]
+    }
+
+    fn instances(&self) -> Vec<Vec<Fr>> {
+        vec![self.instances.clone()]
+    }
+
+    fn accumulator_indices() -> Option<Vec<(usize, usize)>> {
+        Some((0..4 * LIMBS).map(|idx| (0, idx)).collect())
+    }
+
+    fn selectors(config: &Self::Config) -> Vec<Selector> {
+        config.gate().basic_gates[0].iter().map(|gate| gate.q_enable).collect()
+    }
+}
+
+impl Circuit<Fr> for PublicAggregationCircuit {
+    type Config = AggregationConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            svk: self.svk,
+            snarks: self.snarks.iter().map(SnarkWitness::without_witnesses).collect(),
+            has_accumulator: self.has_accumulator.clone(),
+            instances: vec![Fr::zero(); self.instances.len()],
+            as_proof: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut plonk::ConstraintSystem<Fr>) -> Self::Config {
+        let path = std::env::var("VERIFY_CONFIG")
+            .unwrap_or_else(|_| "configs/verify_circuit.config".to_owned());
+        let params: AggregationConfigParams = serde_json::from_reader(
+            File::open(path.as_str()).unwrap_or_else(|_| panic!("{path:?} does not exist")),
+        )
+        .unwrap();
+        // `new` and `synthesize` always verify and accumulate with SHPLONK (see the comment in
+        // `new`), so a config declared for GWC19 is rejected rather than accepted for it.
+        assert_eq!(
+            params.multi_open_scheme,
+            MultiOpenSchemeChoice::Bdfg21,
+            "verify_circuit.config was generated for {:?}, but PublicAggregationCircuit always uses Bdfg21",
+            params.multi_open_scheme
+        );
+
+        AggregationConfig::configure(meta, params)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fr>,
+    ) -> Result<(), plonk::Error> {
+        config.range().load_lookup_table(&mut layouter)?;
+
+        let mut first_pass = halo2_base::SKIP_FIRST_PASS;
+        let mut assigned_instances = vec![];
+
+        layouter.assign_region(
+            || "",
+            |region| {
+                if first_pass {
+                    first_pass = false;
+                    return Ok(());
+                }
+                #[cfg(feature = "display")]
+                let witness_time = start_timer!(|| "Witness Collection");
+                let ctx = Context::new(
+                    region,
+                    ContextParams {
+                        max_rows: config.gate().max_rows,
+                        num_context_ids: 1,
+                        fixed_columns: config.gate().constants.clone(),
+                    },
+                );
+
+                let ecc_chip = config.ecc_chip();
+                let loader = Halo2Loader::new(ecc_chip, ctx);
+                // As in `AggregationCircuit::synthesize`, this still loads every snark's domain
+                // as a constant and drops the witnessed `k`/vkey digest -- `PublicAggregationCircuit`
+                // doesn't yet deliver "one fixed circuit for differing-size snarks" either.
+                let (previous_instances, KzgAccumulator { lhs, rhs }, _) =
+                    aggregate::<Kzg<Bn256, Bdfg21>>(
+                        &self.svk,
+                        &loader,
+                        &self.snarks,
+                        self.as_proof(),
+                        &vec![DomainAs::Constant; self.snarks.len()],
+                        &self.has_accumulator,
+                    );
+
+                let lhs = lhs.assigned();
+                let rhs = rhs.assigned();
+
+                assigned_instances = lhs
+                    .x
+                    .truncation
+                    .limbs
+                    .iter()
+                    .chain(lhs.y.truncation.limbs.iter())
+                    .chain(rhs.x.truncation.limbs.iter())
+                    .chain(rhs.y.truncation.limbs.iter())
+                    .map(|assigned| {
+                        #[cfg(feature = "halo2-axiom")]
+                        {
+                            *assigned.cell()
+                        }
+                        #[cfg(feature = "halo2-pse")]
+                        {
+                            assigned.cell()
+                        }
+                    })
+                    .collect_vec();
+
+                // Forward every instance of every aggregated snark, skipping each snark's own
+                // accumulator limbs when it has one (those are already folded above).
+                let mut offset = 0;
+                for (snark, has_accumulator) in self.snarks.iter().zip(self.has_accumulator.iter())
+                {
+                    let len = snark.instances.iter().map(Vec::len).sum::<usize>();
+                    let skip = if *has_accumulator { 4 * LIMBS } else { 0 };
+                    assigned_instances.extend(
+                        previous_instances[offset + skip..offset + len].iter().map(|scalar| {
+                            #[cfg(feature = "halo2-axiom")]
+                            {
+                                *scalar.assigned().cell()
+                            }
+                            #[cfg(feature = "halo2-pse")]
+                            {
+                                scalar.assigned().cell()
+                            }
+                        }),
+                    );
+                    offset += len;
+                }
+
+                config.base_field_config.finalize(&mut loader.ctx_mut());
+                #[cfg(feature = "display")]
+                end_timer!(witness_time);
+                Ok(())
+            },
+        )?;
+
+        // Expose instances
+        for (i, cell) in assigned_instances.into_iter().enumerate() {
+            layouter.constrain_instance(cell, config.instance, i);
+        }
+        Ok(())
+    }
+}
+
+/// Serializes `params`, trimmed by halo2's own `ParamsKZG::write` to the degree they were
+/// created for, so the same (potentially large) setup doesn't need to be regenerated for every
+/// separate key-generation or proving process that aggregates at this degree.
+pub fn write_params(params: &ParamsKZG<Bn256>, path: impl AsRef<std::path::Path>) {
+    let mut file = File::create(path).expect("failed to create params file");
+    params.write(&mut file).expect("failed to write params");
+}
+
+pub fn read_params(path: impl AsRef<std::path::Path>) -> ParamsKZG<Bn256> {
+    let mut file = File::open(path).expect("params file does not exist");
+    ParamsKZG::read(&mut file).expect("failed to parse params")
+}
+
+/// Serializes an aggregation circuit's proving key so a separate proving process can load it
+/// directly instead of re-running key generation (deterministic, but expensive at aggregation
+/// degree).
+pub fn write_pk(pk: &plonk::ProvingKey<G1Affine>, path: impl AsRef<std::path::Path>) {
+    let mut file = File::create(path).expect("failed to create proving key file");
+    pk.write(&mut file).expect("failed to write proving key");
+}
+
+/// `AS` must match the aggregation circuit the key was generated for; see
+/// `AggregationCircuit::with_break_points` for keeping the layout underneath this key fixed
+/// across that separate key-generation and proving.
+pub fn read_pk<AS>(
+    path: impl AsRef<std::path::Path>,
+    params: &ParamsKZG<Bn256>,
+) -> plonk::ProvingKey<G1Affine>
+where
+    AS: PolynomialCommitmentScheme<
+            G1Affine,
+            NativeLoader,
+            Accumulator = KzgAccumulator<G1Affine, NativeLoader>,
+        > + MultiOpenScheme<G1Affine, NativeLoader>
+        + AccumulationScheme<G1Affine, NativeLoader, Accumulator = KzgAccumulator<G1Affine, NativeLoader>>
+        + AccumulationSchemeProver<G1Affine>
+        + for<'a> PolynomialCommitmentScheme<
+            G1Affine,
+            Rc<Halo2Loader<'a>>,
+            Accumulator = KzgAccumulator<G1Affine, Rc<Halo2Loader<'a>>>,
+        > + for<'a> MultiOpenScheme<G1Affine, Rc<Halo2Loader<'a>>>,
+{
+    let mut file = File::open(path).expect("proving key file does not exist");
+    plonk::ProvingKey::read::<_, AggregationCircuit<AS>>(&mut file, params)
+        .expect("failed to parse proving key")
+}
+
+/// Persists the break points captured from a key-generation run so a later proving run can load
+/// them back via [`read_break_points`] and construct its `AggregationCircuit` with
+/// `with_break_points`, keeping the circuit layout underneath a serialized proving key fixed.
+pub fn write_break_points(path: impl AsRef<std::path::Path>, break_points: &BreakPoints) {
+    let file = File::create(path).expect("failed to create break points file");
+    serde_json::to_writer_pretty(file, break_points).expect("failed to write break points");
+}
+
+pub fn read_break_points(path: impl AsRef<std::path::Path>) -> BreakPoints {
+    let file = File::open(path).expect("break points file does not exist");
+    serde_json::from_reader(file).expect("failed to parse break points")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_base::halo2_proofs::dev::MockProver;
+
+    const TEST_DEGREE: u32 = 18;
+
+    fn test_config_params() -> AggregationConfigParams {
+        AggregationConfigParams {
+            strategy: halo2_ecc::fields::fp::FpStrategy::Simple,
+            degree: TEST_DEGREE,
+            num_advice: 2,
+            num_lookup_advice: 1,
+            num_fixed: 1,
+            lookup_bits: TEST_DEGREE as usize - 1,
+            limb_bits: BITS,
+            num_limbs: LIMBS,
+            multi_open_scheme: MultiOpenSchemeChoice::Bdfg21,
+        }
+    }
+
+    /// Exercises `derive_domain_as_witness` (and, through it, `select_from_table`) the way
+    /// `aggregate` drives it under `DomainAs::PreprocessedAndDomainAsWitness`: witness a `k`,
+    /// derive `(k, n, omega)` in-circuit, and check the result against the native values.
+    struct DeriveDomainCircuit {
+        k: Value<u32>,
+    }
+
+    impl Circuit<Fr> for DeriveDomainCircuit {
+        type Config = AggregationConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self { k: Value::unknown() }
+        }
+
+        fn configure(meta: &mut plonk::ConstraintSystem<Fr>) -> Self::Config {
+            AggregationConfig::configure(meta, test_config_params())
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fr>,
+        ) -> Result<(), plonk::Error> {
+            let mut first_pass = halo2_base::SKIP_FIRST_PASS;
+            let mut assigned_instances = vec![];
+
+            layouter.assign_region(
+                || "",
+                |region| {
+                    if first_pass {
+                        first_pass = false;
+                        return Ok(());
+                    }
+                    let ctx = Context::new(
+                        region,
+                        ContextParams {
+                            max_rows: config.gate().max_rows,
+                            num_context_ids: 1,
+                            fixed_columns: config.gate().constants.clone(),
+                        },
+                    );
+
+                    let ecc_chip = config.ecc_chip();
+                    let loader = Halo2Loader::new(ecc_chip, ctx);
+                    let (k, n, omega) = derive_domain_as_witness(&loader, self.k);
+                    assigned_instances = [k, n, omega]
+                        .iter()
+                        .map(|scalar| {
+                            #[cfg(feature = "halo2-axiom")]
+                            {
+                                *scalar.assigned().cell()
+                            }
+                            #[cfg(feature = "halo2-pse")]
+                            {
+                                scalar.assigned().cell()
+                            }
+                        })
+                        .collect_vec();
+
+                    config.base_field_config.finalize(&mut loader.ctx_mut());
+                    Ok(())
+                },
+            )?;
+
+            for (i, cell) in assigned_instances.into_iter().enumerate() {
+                layouter.constrain_instance(cell, config.instance, i)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Exercises `accumulator_from_limbs` the way `aggregate` drives it when folding an inner
+    /// accumulator: witness `4 * LIMBS` limbs and check the resulting `lhs`/`rhs` points' own
+    /// limbs come back in the same order they went in -- i.e. that the `[..2 * LIMBS]` /
+    /// `[2 * LIMBS..]` / further `[..LIMBS]` / `[LIMBS..]` slicing lines up `x`/`y` and
+    /// `lhs`/`rhs` the way `instances[0][..4 * LIMBS]` is assumed to be laid out.
+    struct AccumulatorFromLimbsCircuit {
+        limbs: [Value<Fr>; 4 * LIMBS],
+    }
+
+    impl Circuit<Fr> for AccumulatorFromLimbsCircuit {
+        type Config = AggregationConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self { limbs: [Value::unknown(); 4 * LIMBS] }
+        }
+
+        fn configure(meta: &mut plonk::ConstraintSystem<Fr>) -> Self::Config {
+            AggregationConfig::configure(meta, test_config_params())
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fr>,
+        ) -> Result<(), plonk::Error> {
+            let mut first_pass = halo2_base::SKIP_FIRST_PASS;
+            let mut assigned_instances = vec![];
+
+            layouter.assign_region(
+                || "",
+                |region| {
+                    if first_pass {
+                        first_pass = false;
+                        return Ok(());
+                    }
+                    let ctx = Context::new(
+                        region,
+                        ContextParams {
+                            max_rows: config.gate().max_rows,
+                            num_context_ids: 1,
+                            fixed_columns: config.gate().constants.clone(),
+                        },
+                    );
+
+                    let ecc_chip = config.ecc_chip();
+                    let loader = Halo2Loader::new(ecc_chip, ctx);
+                    let limbs =
+                        self.limbs.iter().map(|limb| loader.assign_scalar(*limb)).collect_vec();
+                    let KzgAccumulator { lhs, rhs } = accumulator_from_limbs(&loader, &limbs);
+                    let lhs = lhs.assigned();
+                    let rhs = rhs.assigned();
+
+                    assigned_instances = lhs
+                        .x
+                        .truncation
+                        .limbs
+                        .iter()
+                        .chain(lhs.y.truncation.limbs.iter())
+                        .chain(rhs.x.truncation.limbs.iter())
+                        .chain(rhs.y.truncation.limbs.iter())
+                        .map(|assigned| {
+                            #[cfg(feature = "halo2-axiom")]
+                            {
+                                *assigned.cell()
+                            }
+                            #[cfg(feature = "halo2-pse")]
+                            {
+                                assigned.cell()
+                            }
+                        })
+                        .collect_vec();
+
+                    config.base_field_config.finalize(&mut loader.ctx_mut());
+                    Ok(())
+                },
+            )?;
+
+            for (i, cell) in assigned_instances.into_iter().enumerate() {
+                layouter.constrain_instance(cell, config.instance, i)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn accumulator_from_limbs_preserves_limb_order() {
+        let limb_values: Vec<Fr> = (0..4 * LIMBS as u64).map(Fr::from).collect();
+        let limbs: [Value<Fr>; 4 * LIMBS] =
+            limb_values.iter().map(|limb| Value::known(*limb)).collect_vec().try_into().unwrap();
+
+        let circuit = AccumulatorFromLimbsCircuit { limbs };
+        MockProver::run(TEST_DEGREE, &circuit, vec![limb_values]).unwrap().assert_satisfied();
+    }
+
+    #[test]
+    fn derive_domain_as_witness_matches_native() {
+        // `K_MAX + 1 = 25` entries in the tables `derive_domain_as_witness` selects from -- not a
+        // power of two -- so this also covers the `select_from_table` padding/bit-width fix: every
+        // one of these `k`s exercises a different bit pattern over the padded 32-entry tables.
+        for k in [0u32, 1, 2, 7, 13, 23, K_MAX] {
+            let n = 1u64 << k;
+            let omega = Fr::root_of_unity().pow_vartime([1u64 << (Fr::S - k)]);
+            let public_inputs = vec![Fr::from(k as u64), Fr::from(n), omega];
+
+            let circuit = DeriveDomainCircuit { k: Value::known(k) };
+            MockProver::run(TEST_DEGREE, &circuit, vec![public_inputs])
+                .unwrap()
+                .assert_satisfied();
+        }
+    }
+
+    /// Covers `AggregationCircuit::configure`'s check that `verify_circuit.config`'s
+    /// `multi_open_scheme` matches the `AS` the circuit was generalized over: a GWC19 circuit
+    /// configured against a config file written for SHPLONK must fail loudly instead of silently
+    /// accepting a config declared for the other scheme.
+    #[test]
+    fn aggregation_circuit_configure_checks_multi_open_scheme() {
+        let mut params = test_config_params();
+        params.multi_open_scheme = MultiOpenSchemeChoice::Bdfg21;
+        let path = std::env::temp_dir()
+            .join("plonk_verifier_aggregation_circuit_configure_checks_multi_open_scheme.config");
+        std::fs::write(&path, serde_json::to_string(&params).unwrap()).unwrap();
+        std::env::set_var("VERIFY_CONFIG", &path);
+
+        // Matching scheme: configuring the SHPLONK circuit against the SHPLONK config succeeds.
+        AggregationCircuit::<Kzg<Bn256, Bdfg21>>::configure(&mut plonk::ConstraintSystem::default());
+
+        // Mismatched scheme: configuring the GWC19 circuit against the same config must panic.
+        let mismatched = std::panic::catch_unwind(|| {
+            AggregationCircuit::<Kzg<Bn256, Gwc19>>::configure(&mut plonk::ConstraintSystem::default())
+        });
+        assert!(mismatched.is_err());
+
+        std::env::remove_var("VERIFY_CONFIG");
+        std::fs::remove_file(&path).ok();
+    }
+}